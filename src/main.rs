@@ -1,5 +1,9 @@
 use std::{
+    fs,
     thread,
+    rc::Rc,
+    cell::RefCell,
+    collections::HashMap,
     time::Duration
 };
 
@@ -29,6 +33,254 @@ pub struct Point2<T>
 
 const FPS: usize = 60;
 
+// the three render paths exposed by SDL2_ttf
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextMode
+{
+    Blended{fg: Color},
+    Solid{fg: Color},
+    Shaded{fg: Color, bg: Color}
+}
+
+impl TextMode
+{
+    // keeps the render path but swaps in the colors for the pane being drawn
+    fn with_colors(self, fg: Color, bg: Color) -> Self
+    {
+        match self
+        {
+            Self::Blended{..} => Self::Blended{fg},
+            Self::Solid{..} => Self::Solid{fg},
+            Self::Shaded{..} => Self::Shaded{fg, bg}
+        }
+    }
+
+    fn cycle(self) -> Self
+    {
+        match self
+        {
+            Self::Blended{fg} => Self::Solid{fg},
+            Self::Solid{fg} => Self::Shaded{fg, bg: Color::RGB(0, 0, 0)},
+            Self::Shaded{fg, ..} => Self::Blended{fg}
+        }
+    }
+}
+
+// the on-screen buttons drawn by the optional touch-control layer
+#[derive(Debug, Clone, Copy)]
+pub enum TouchButton
+{
+    Paste,
+    Clear,
+    ModeToggle,
+    Backspace
+}
+
+impl TouchButton
+{
+    const ALL: [Self; 4] = [Self::Paste, Self::Clear, Self::ModeToggle, Self::Backspace];
+
+    fn label(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Paste => "paste",
+            Self::Clear => "clear",
+            Self::ModeToggle => "mode",
+            Self::Backspace => "back"
+        }
+    }
+}
+
+// maps base64 characters onto their 6-bit values; padding (`=`) is handled by
+// the decoder itself and is never part of an alphabet
+#[derive(Debug, Clone)]
+pub enum Alphabet
+{
+    Standard,
+    UrlSafe,
+    Custom(Vec<char>)
+}
+
+impl Alphabet
+{
+    fn value(&self, original_char: char) -> Option<u8>
+    {
+        let c = original_char as u32;
+
+        let value = match self
+        {
+            Self::Standard | Self::UrlSafe =>
+            {
+                let (plus, slash) = match self
+                {
+                    Self::UrlSafe => ('-', '_'),
+                    _ => ('+', '/')
+                };
+
+                if (0x41..=0x5a).contains(&c)
+                {
+                    Some(c - 0x41)
+                } else if (0x61..=0x7a).contains(&c)
+                {
+                    Some(c - 0x61 + 26)
+                } else if (0x30..=0x39).contains(&c)
+                {
+                    Some(c - 0x30 + 52)
+                } else if original_char == plus
+                {
+                    Some(62)
+                } else if original_char == slash
+                {
+                    Some(63)
+                } else
+                {
+                    None
+                }
+            },
+            Self::Custom(chars) =>
+            {
+                chars.iter().position(|&ch| ch == original_char).map(|i| i as u32)
+            }
+        };
+
+        value.map(|x| x as u8)
+    }
+
+    fn name(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Standard => "standard",
+            Self::UrlSafe => "url-safe",
+            Self::Custom(_) => "custom"
+        }
+    }
+
+    // in strict mode the stream must be a whole number of 4-character groups
+    // with `=` appearing only as one or two trailing padding characters
+    fn validate_strict(&self, text: &str) -> Result<(), String>
+    {
+        let len = text.chars().count();
+        if len % 4 != 0
+        {
+            return Err(format!("length {len} is not a multiple of 4"));
+        }
+
+        let padding = text.chars().rev().take_while(|&c| c == '=').count();
+        if padding > 2
+        {
+            return Err(format!("{padding} padding characters"));
+        }
+
+        for (index, c) in text.chars().enumerate()
+        {
+            if c == '='
+            {
+                if index < len - padding
+                {
+                    return Err("padding '=' before end of stream".to_owned());
+                }
+            } else if self.value(c).is_none()
+            {
+                return Err(format!("invalid character '{c}'"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Theme
+{
+    pub background: Color,
+    pub input: Color,
+    pub decoded: Color
+}
+
+const THEMES: [Theme; 3] = [
+    Theme{
+        background: Color::RGB(0, 0, 0),
+        input: Color::RGB(255, 255, 255),
+        decoded: Color::RGB(120, 220, 120)
+    },
+    Theme{
+        background: Color::RGB(255, 255, 255),
+        input: Color::RGB(0, 0, 0),
+        decoded: Color::RGB(40, 90, 200)
+    },
+    Theme{
+        background: Color::RGB(20, 20, 30),
+        input: Color::RGB(240, 220, 120),
+        decoded: Color::RGB(120, 220, 240)
+    }
+];
+
+// identifies a single rendered line: its text, the font point size and the
+// render mode (which carries the colors), so a cached texture is only reused
+// when it would be pixel-for-pixel identical
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct TextureKey
+{
+    text: String,
+    point: u16,
+    mode: TextMode
+}
+
+// bounded least-recently-used cache of rendered line textures; textures are
+// shared via `Rc` so the same entry can sit in the cache and be blitted
+struct TextureCache<'a>
+{
+    capacity: usize,
+    entries: HashMap<TextureKey, (Rect, Rc<Texture<'a>>)>,
+    order: Vec<TextureKey>
+}
+
+impl<'a> TextureCache<'a>
+{
+    fn new(capacity: usize) -> Self
+    {
+        Self{
+            capacity,
+            entries: HashMap::new(),
+            order: Vec::new()
+        }
+    }
+
+    fn touch(&mut self, key: &TextureKey)
+    {
+        if let Some(index) = self.order.iter().position(|k| k == key)
+        {
+            let key = self.order.remove(index);
+            self.order.push(key);
+        }
+    }
+
+    fn get(&mut self, key: &TextureKey) -> Option<(Rect, Rc<Texture<'a>>)>
+    {
+        let entry = self.entries.get(key).cloned();
+        if entry.is_some()
+        {
+            self.touch(key);
+        }
+
+        entry
+    }
+
+    fn insert(&mut self, key: TextureKey, value: (Rect, Rc<Texture<'a>>))
+    {
+        while self.order.len() >= self.capacity && !self.order.is_empty()
+        {
+            let evicted = self.order.remove(0);
+            self.entries.remove(&evicted);
+        }
+
+        self.entries.insert(key.clone(), value);
+        self.order.push(key);
+    }
+}
+
 pub struct Assets<'a>
 {
     texture_creator: &'a TextureCreator<WindowContext>
@@ -117,49 +369,251 @@ struct Game<'a>
     window: GameWindow<'a>,
     ttf_ctx: &'a Sdl2TtfContext,
     font: Font<'a, 'static>,
-    text_texture: Option<(Rect, Texture<'a>)>,
-    decoded_texture: Option<(Rect, Texture<'a>)>,
+    mono_font: Font<'a, 'static>,
+    text_texture: Vec<(Rect, Rc<Texture<'a>>)>,
+    decoded_texture: Vec<(Rect, Rc<Texture<'a>>)>,
     current_text: String,
-    decoded_text: String
+    decoded_text: String,
+    text_mode: TextMode,
+    theme_index: usize,
+    point_size: u16,
+    alphabet: Alphabet,
+    custom_alphabet: Option<Alphabet>,
+    strict: bool,
+    decoded_bytes: Vec<u8>,
+    hex_view: bool,
+    touch_enabled: bool,
+    texture_cache: RefCell<TextureCache<'a>>
 }
 
+// default paths used by the load/save hotkeys; overridable via environment
+const INPUT_PATH: &str = "input.txt";
+const OUTPUT_PATH: &str = "decoded.bin";
+
 impl<'a> Game<'a>
 {
     pub fn new(window: GameWindow<'a>, ttf_ctx: &'a Sdl2TtfContext) -> Self
     {
-        let font = Self::create_font(ttf_ctx, 20);
+        let point_size = 20;
+        let font = Self::create_font(ttf_ctx, point_size);
+        let mono_font = Self::create_mono_font(ttf_ctx, point_size);
 
         Self{
             window,
             ttf_ctx,
             font,
-            text_texture: None,
-            decoded_texture: None,
+            mono_font,
+            text_texture: Vec::new(),
+            decoded_texture: Vec::new(),
             current_text: String::new(),
-            decoded_text: String::new()
+            decoded_text: String::new(),
+            text_mode: TextMode::Blended{fg: Color::RGB(255, 255, 255)},
+            theme_index: 0,
+            point_size,
+            alphabet: Alphabet::Standard,
+            custom_alphabet: Self::custom_alphabet_from_env(),
+            strict: false,
+            decoded_bytes: Vec::new(),
+            hex_view: false,
+            touch_enabled: std::env::var("BASE64_TOUCH").is_ok(),
+            texture_cache: RefCell::new(TextureCache::new(256))
+        }
+    }
+
+    fn theme(&self) -> Theme
+    {
+        THEMES[self.theme_index]
+    }
+
+    // a custom alphabet supplied via the environment, if it is exactly 64 chars
+    fn custom_alphabet_from_env() -> Option<Alphabet>
+    {
+        let chars: Vec<char> = std::env::var("BASE64_ALPHABET").ok()?.chars().collect();
+
+        if chars.len() == 64
+        {
+            Some(Alphabet::Custom(chars))
+        } else
+        {
+            eprintln!("BASE64_ALPHABET must be exactly 64 characters, ignoring");
+
+            None
         }
     }
 
-    fn create_text_texture(&self, text: &str) -> Option<(Rect, Texture<'a>)>
+    // cycles through the available alphabets, including a custom one if supplied
+    fn cycle_alphabet(&mut self)
     {
-        self.font.render(text).blended(Color::RGB(255, 255, 255)).ok().map(|surface|
+        self.alphabet = match &self.alphabet
+        {
+            Alphabet::Standard => Alphabet::UrlSafe,
+            Alphabet::UrlSafe => self.custom_alphabet.clone().unwrap_or(Alphabet::Standard),
+            Alphabet::Custom(_) => Alphabet::Standard
+        };
+    }
+
+    fn create_text_texture(&self, text: &str, mode: TextMode) -> Option<(Rect, Rc<Texture<'a>>)>
+    {
+        let key = TextureKey{text: text.to_owned(), point: self.point_size, mode};
+
+        if let Some(entry) = self.texture_cache.borrow_mut().get(&key)
+        {
+            return Some(entry);
+        }
+
+        let builder = self.font.render(text);
+
+        let surface = match mode
+        {
+            TextMode::Blended{fg} => builder.blended(fg),
+            TextMode::Solid{fg} => builder.solid(fg),
+            TextMode::Shaded{fg, bg} => builder.shaded(fg, bg)
+        };
+
+        surface.ok().map(|surface|
         {
             let texture_creator = self.window.assets.texture_creator();
             let rect = surface.rect();
 
-            (rect, texture_creator.create_texture_from_surface(surface).unwrap())
+            let texture = Rc::new(texture_creator.create_texture_from_surface(surface).unwrap());
+
+            self.texture_cache.borrow_mut().insert(key, (rect, Rc::clone(&texture)));
+
+            (rect, texture)
         })
     }
 
+    // greedily wraps `text` into lines no wider than the window, hard-breaking
+    // any single word that cannot fit on a line of its own
+    fn wrap_lines(&self, text: &str) -> Vec<String>
+    {
+        let max_width = self.window.window.window_size.x;
+
+        let fits = |candidate: &str|
+        {
+            self.font.size_of(candidate).map(|(width, _)| width <= max_width).unwrap_or(true)
+        };
+
+        let mut lines = Vec::new();
+        let mut current = String::new();
+
+        let mut push_word = |word: &str, lines: &mut Vec<String>, current: &mut String|
+        {
+            let candidate = if current.is_empty()
+            {
+                word.to_owned()
+            } else
+            {
+                format!("{current} {word}")
+            };
+
+            if fits(&candidate)
+            {
+                *current = candidate;
+                return;
+            }
+
+            // the word doesn't fit on the current line; flush whatever is there
+            if !current.is_empty()
+            {
+                lines.push(std::mem::take(current));
+            }
+
+            if fits(word)
+            {
+                *current = word.to_owned();
+            } else
+            {
+                // a single word wider than the window, hard-break it
+                for c in word.chars()
+                {
+                    let candidate = format!("{current}{c}");
+                    if fits(&candidate) || current.is_empty()
+                    {
+                        current.push(c);
+                    } else
+                    {
+                        lines.push(std::mem::take(current));
+                        current.push(c);
+                    }
+                }
+            }
+        };
+
+        // explicit newlines always force a line break
+        for segment in text.split('\n')
+        {
+            for word in segment.split_whitespace()
+            {
+                push_word(word, &mut lines, &mut current);
+            }
+
+            if !current.is_empty()
+            {
+                lines.push(std::mem::take(&mut current));
+            }
+        }
+
+        lines
+    }
+
+    fn create_text_textures(&self, text: &str, fg: Color) -> Vec<(Rect, Rc<Texture<'a>>)>
+    {
+        let mode = self.text_mode.with_colors(fg, self.theme().background);
+
+        self.wrap_lines(text).iter()
+            .filter_map(|line| self.create_text_texture(line, mode))
+            .collect()
+    }
+
+    // renders each already-formatted line with the monospace font and no
+    // wrapping, so the hex dump's column spacing is preserved verbatim
+    fn create_mono_textures(&self, text: &str, fg: Color) -> Vec<(Rect, Rc<Texture<'a>>)>
+    {
+        let mode = self.text_mode.with_colors(fg, self.theme().background);
+
+        text.split('\n').filter(|line| !line.is_empty()).filter_map(|line|
+        {
+            let builder = self.mono_font.render(line);
+
+            let surface = match mode
+            {
+                TextMode::Blended{fg} => builder.blended(fg),
+                TextMode::Solid{fg} => builder.solid(fg),
+                TextMode::Shaded{fg, bg} => builder.shaded(fg, bg)
+            };
+
+            surface.ok().map(|surface|
+            {
+                let texture_creator = self.window.assets.texture_creator();
+                let rect = surface.rect();
+
+                (rect, Rc::new(texture_creator.create_texture_from_surface(surface).unwrap()))
+            })
+        }).collect()
+    }
+
     fn create_font(ttf_ctx: &'a Sdl2TtfContext, point: u16) -> Font<'a, 'static>
     {
         ttf_ctx.load_font("font/OpenSans-Regular.ttf", point).unwrap()
     }
 
+    // the hex view needs fixed-width glyphs so the offset/hex/ascii columns line up
+    fn create_mono_font(ttf_ctx: &'a Sdl2TtfContext, point: u16) -> Font<'a, 'static>
+    {
+        ttf_ctx.load_font("font/DejaVuSansMono.ttf", point).unwrap()
+    }
+
     #[allow(dead_code)]
     fn recreate_font(&mut self, point: u16)
     {
         self.font = Self::create_font(self.ttf_ctx, point);
+        self.mono_font = Self::create_mono_font(self.ttf_ctx, point);
+        self.point_size = point;
+
+        // cached textures were rendered at the previous size
+        self.texture_cache.borrow_mut().entries.clear();
+        self.texture_cache.borrow_mut().order.clear();
     }
 
     fn on_event(&mut self, event: Event) -> bool
@@ -170,7 +624,35 @@ impl<'a> Game<'a>
             Event::Window{win_event: WindowEvent::Resized(x, y), ..} =>
             {
                 self.window.window.window_size = Point2{x: x as u32, y: y as u32};
+
+                // keep text legible as the window shrinks to phone sizes
+                if self.touch_enabled
+                {
+                    let point = self.scaled_point();
+                    if point != self.point_size
+                    {
+                        self.recreate_font(point);
+                    }
+                }
+
+                self.recreate_textures();
             },
+            Event::FingerDown{x, y, ..} if self.touch_enabled =>
+            {
+                let window_size = *self.window_size();
+                let px = (x * window_size.x as f32) as i32;
+                let py = (y * window_size.y as f32) as i32;
+
+                for (button, rect) in self.touch_buttons()
+                {
+                    if rect.contains_point((px, py))
+                    {
+                        self.touch_action(button);
+                        break;
+                    }
+                }
+            },
+            Event::FingerUp{..} | Event::FingerMotion{..} => (),
             Event::TextInput{text, ..} =>
             {
                 self.add_text(&text);
@@ -189,11 +671,50 @@ impl<'a> Game<'a>
                     },
                     Scancode::V if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
                     {
-                        match self.window.window.video.clipboard().clipboard_text()
-                        {
-                            Ok(text) => self.add_text(&text),
-                            Err(err) => eprintln!("clipboard error: {err}")
-                        }
+                        self.paste();
+                    },
+                    Scancode::M if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
+                    {
+                        self.text_mode = self.text_mode.cycle();
+                        self.recreate_textures();
+                    },
+                    Scancode::T if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
+                    {
+                        self.theme_index = (self.theme_index + 1) % THEMES.len();
+                        self.recreate_textures();
+                    },
+                    Scancode::A if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
+                    {
+                        self.cycle_alphabet();
+                        self.update_text();
+                    },
+                    Scancode::S if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
+                    {
+                        self.strict = !self.strict;
+                        self.update_text();
+                    },
+                    Scancode::O if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
+                    {
+                        let path = std::env::var("BASE64_INPUT")
+                            .unwrap_or_else(|_| INPUT_PATH.to_owned());
+
+                        self.load_file(&path);
+                    },
+                    Scancode::E if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
+                    {
+                        let path = std::env::var("BASE64_OUTPUT")
+                            .unwrap_or_else(|_| OUTPUT_PATH.to_owned());
+
+                        self.save_file(&path);
+                    },
+                    Scancode::H if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
+                    {
+                        self.hex_view = !self.hex_view;
+                        self.update_text();
+                    },
+                    Scancode::B if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) =>
+                    {
+                        self.touch_enabled = !self.touch_enabled;
                     },
                     _ => ()
                 }
@@ -220,16 +741,110 @@ impl<'a> Game<'a>
 
     fn update_text(&mut self)
     {
-        self.decoded_text = Self::decode_text(&self.current_text);
+        self.decoded_bytes = self.decode_text_raw();
+        self.decoded_text = self.decode_text();
 
         self.recreate_textures();
     }
 
+    fn paste(&mut self)
+    {
+        match self.window.window.video.clipboard().clipboard_text()
+        {
+            Ok(text) => self.add_text(&text),
+            Err(err) => eprintln!("clipboard error: {err}")
+        }
+    }
+
+    // font point size chosen relative to the window height for mobile layouts
+    fn scaled_point(&self) -> u16
+    {
+        ((self.window_size().y / 20).clamp(12, 48)) as u16
+    }
+
+    // button rects anchored along the bottom edge, scaled to the window
+    fn touch_buttons(&self) -> Vec<(TouchButton, Rect)>
+    {
+        let window_size = *self.window_size();
+
+        let count = TouchButton::ALL.len() as u32;
+        let button_width = window_size.x / count;
+        let button_height = (window_size.y / 8).max(40);
+        let y = window_size.y as i32 - button_height as i32;
+
+        TouchButton::ALL.iter().enumerate().map(|(index, &button)|
+        {
+            let x = index as i32 * button_width as i32;
+
+            (button, Rect::new(x, y, button_width, button_height))
+        }).collect()
+    }
+
+    fn touch_action(&mut self, button: TouchButton)
+    {
+        match button
+        {
+            TouchButton::Paste => self.paste(),
+            TouchButton::Clear =>
+            {
+                self.current_text.clear();
+                self.update_text();
+            },
+            TouchButton::ModeToggle =>
+            {
+                self.text_mode = self.text_mode.cycle();
+                self.recreate_textures();
+            },
+            TouchButton::Backspace => self.remove_char()
+        }
+    }
+
+    fn load_file(&mut self, path: &str)
+    {
+        match fs::read_to_string(path)
+        {
+            Ok(text) =>
+            {
+                self.current_text = text;
+                self.update_text();
+            },
+            Err(err) => eprintln!("failed to read {path}: {err}")
+        }
+    }
+
+    fn save_file(&self, path: &str)
+    {
+        // in strict mode the pane shows an error marker for malformed input, so
+        // refuse to save bytes that wouldn't match what's on screen
+        if self.strict
+        {
+            if let Err(err) = self.alphabet.validate_strict(&self.current_text)
+            {
+                eprintln!("refusing to save, input is invalid: {err}");
+
+                return;
+            }
+        }
+
+        if let Err(err) = fs::write(path, &self.decoded_bytes)
+        {
+            eprintln!("failed to write {path}: {err}");
+        }
+    }
+
     fn recreate_textures(&mut self)
     {
-        self.text_texture = self.create_text_texture(&self.current_text);
+        let theme = self.theme();
 
-        self.decoded_texture = self.create_text_texture(&self.decoded_text);
+        self.text_texture = self.create_text_textures(&self.current_text, theme.input);
+
+        self.decoded_texture = if self.hex_view
+        {
+            self.create_mono_textures(&self.decoded_text, theme.decoded)
+        } else
+        {
+            self.create_text_textures(&self.decoded_text, theme.decoded)
+        };
     }
 
     fn canvas(&mut self) -> &mut Canvas<Window>
@@ -244,75 +859,119 @@ impl<'a> Game<'a>
 
     fn single_frame(&mut self)
     {
-        self.canvas().set_draw_color(Color::RGB(0, 0, 0));
+        let background = self.theme().background;
+        self.canvas().set_draw_color(background);
         self.canvas().clear();
 
         let window_size = *self.window_size();
 
-        let calculate_sizes = |width, height|
+        // input grows downward from the top
+        let mut y = 0;
+        for (text_rect, texture) in self.text_texture.iter()
         {
-            let mut new_width = width;
-            let mut new_height = height;
+            let (width, height) = (text_rect.width(), text_rect.height());
 
-            let ratio = window_size.x as f32 / width as f32;
-            if ratio < 1.0
-            {
-                new_width = window_size.x;
-                new_height = (height as f32 * ratio) as u32;
-            }
+            self.window.canvas().copy(
+                texture,
+                None,
+                Rect::new(0, y, width, height)
+            ).unwrap();
 
-            (new_width, new_height)
-        };
+            y += height as i32;
+        }
 
-        if let Some((text_rect, texture)) = self.text_texture.as_ref()
+        // decoded grows upward from the bottom
+        let total_height: u32 = self.decoded_texture.iter()
+            .map(|(rect, _)| rect.height())
+            .sum();
+
+        let mut y = window_size.y as i32 - total_height as i32;
+        for (text_rect, texture) in self.decoded_texture.iter()
         {
-            let (width, height) = calculate_sizes(text_rect.width(), text_rect.height());
+            let (width, height) = (text_rect.width(), text_rect.height());
 
             self.window.canvas().copy(
                 texture,
                 None,
-                Rect::new(0, 0, width, height)
+                Rect::new(0, y, width, height)
             ).unwrap();
+
+            y += height as i32;
         }
 
-        if let Some((text_rect, texture)) = self.decoded_texture.as_ref()
-        {
-            let (width, height) = calculate_sizes(text_rect.width(), text_rect.height());
+        // status line: active alphabet and padding mode, anchored top-right
+        let status = format!(
+            "{} | {}",
+            self.alphabet.name(),
+            if self.strict { "strict" } else { "lenient" }
+        );
 
-            let y = window_size.y as i32 - height as i32;
+        let mode = self.text_mode.with_colors(self.theme().input, self.theme().background);
+        if let Some((rect, texture)) = self.create_text_texture(&status, mode)
+        {
+            let x = window_size.x as i32 - rect.width() as i32;
 
             self.window.canvas().copy(
-                texture,
+                texture.as_ref(),
                 None,
-                Rect::new(0, y, width, height)
+                Rect::new(x, 0, rect.width(), rect.height())
             ).unwrap();
         }
 
+        // optional on-screen touch controls, drawn over everything else
+        if self.touch_enabled
+        {
+            let theme = self.theme();
+            let label_mode = self.text_mode.with_colors(theme.input, theme.decoded);
+
+            for (button, rect) in self.touch_buttons()
+            {
+                self.canvas().set_draw_color(theme.decoded);
+                self.window.canvas().fill_rect(rect).unwrap();
+
+                self.canvas().set_draw_color(theme.input);
+                self.window.canvas().draw_rect(rect).unwrap();
+
+                if let Some((label_rect, texture)) = self.create_text_texture(button.label(), label_mode)
+                {
+                    let x = rect.x() + (rect.width() as i32 - label_rect.width() as i32) / 2;
+                    let y = rect.y() + (rect.height() as i32 - label_rect.height() as i32) / 2;
+
+                    self.window.canvas().copy(
+                        texture.as_ref(),
+                        None,
+                        Rect::new(x, y, label_rect.width(), label_rect.height())
+                    ).unwrap();
+                }
+            }
+        }
+
         self.canvas().present();
     }
 
-    fn decode_text(text: &str) -> String
+    fn decode_text(&self) -> String
     {
-        let mut values = Self::decode_text_raw(text);
-
-        loop
+        if self.strict
         {
-            if let Some(&last_value) = values.last()
-            {
-                if last_value == 0
-                {
-                    values.pop();
-                } else
-                {
-                    break;
-                }
-            } else
+            if let Err(err) = self.alphabet.validate_strict(&self.current_text)
             {
-                break;
+                return format!("{} invalid: {err}", char::REPLACEMENT_CHARACTER);
             }
         }
 
-        let decoded = String::from_utf8_lossy(&values).into_owned();
+        if self.hex_view
+        {
+            return Self::hex_dump(&self.decoded_bytes);
+        }
+
+        // trailing zero bytes are trimmed for the string view only, so the
+        // raw byte vector used by hex/file-save keeps every decoded byte
+        let end = self.decoded_bytes.iter()
+            .rposition(|&b| b != 0)
+            .map(|i| i + 1)
+            .unwrap_or(0);
+
+        let decoded = String::from_utf8_lossy(&self.decoded_bytes[..end]).into_owned();
 
         decoded.replace(|c: char|
         {
@@ -320,9 +979,40 @@ impl<'a> Game<'a>
         }, &char::REPLACEMENT_CHARACTER.to_string())
     }
 
-    fn decode_text_raw(text: &str) -> Vec<u8>
+    // classic hex dump: 8-digit offset, 16 hex columns and an ASCII gutter
+    fn hex_dump(bytes: &[u8]) -> String
+    {
+        let mut out = String::new();
+
+        for (row, chunk) in bytes.chunks(16).enumerate()
+        {
+            let mut hex = String::new();
+            let mut ascii = String::new();
+
+            for &b in chunk
+            {
+                hex += &format!("{b:02x} ");
+                ascii.push(if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' });
+            }
+
+            out += &format!("{:08x}  {:<48}{ascii}\n", row * 16, hex);
+        }
+
+        out
+    }
+
+    fn decode_text_raw(&self) -> Vec<u8>
     {
-        let total_bits = text.len() * 6;
+        // keep only the symbols that belong to the alphabet (or `=` padding,
+        // which occupies a slot as a zero value); whitespace and other stray
+        // characters — e.g. the newlines in PEM/wrapped base64 — are dropped
+        // entirely rather than advancing the bit cursor past them
+        let symbols: Vec<u8> = self.current_text.chars().filter_map(|c|
+        {
+            if c == '=' { Some(0) } else { self.alphabet.value(c) }
+        }).collect();
+
+        let total_bits = symbols.len() * 6;
 
         let full_bytes = total_bits / 8;
         let padding_bytes = if (total_bits % 8) == 0 { 0 } else { 1 };
@@ -330,26 +1020,23 @@ impl<'a> Game<'a>
         let mut current_bit = 0;
         let mut values = vec![0; full_bytes + padding_bytes];
 
-        for c in text.chars()
+        for index in symbols
         {
-            if let Some(index) = Self::decode_single(c)
-            {
-                let current_byte = current_bit / 8;
+            let current_byte = current_bit / 8;
 
-                let bit_remainder = current_bit % 8;
-                if bit_remainder > 2
-                {
-                    // doesnt fit in the current byte cleanly
-                    let shift = bit_remainder - 2;
-                    values[current_byte] |= index >> shift;
+            let bit_remainder = current_bit % 8;
+            if bit_remainder > 2
+            {
+                // doesnt fit in the current byte cleanly
+                let shift = bit_remainder - 2;
+                values[current_byte] |= index >> shift;
 
-                    let next_shift = 10 - bit_remainder;
-                    values[current_byte + 1] |= index << next_shift;
-                } else
-                {
-                    let shift = 2 - bit_remainder;
-                    values[current_byte] |= index << shift;
-                }
+                let next_shift = 10 - bit_remainder;
+                values[current_byte + 1] |= index << next_shift;
+            } else
+            {
+                let shift = 2 - bit_remainder;
+                values[current_byte] |= index << shift;
             }
 
             current_bit += 6;
@@ -357,38 +1044,6 @@ impl<'a> Game<'a>
 
         values
     }
-
-    fn decode_single(original_char: char) -> Option<u8>
-    {
-        let c = original_char as u32;
-
-        let value = if (0x41..=0x5a).contains(&c)
-        {
-            Some(c - 0x41)
-        } else if (0x61..=0x7a).contains(&c)
-        {
-            Some(c - 0x61 + 26)
-        } else if (0x30..=0x39).contains(&c)
-        {
-            Some(c - 0x30 + 52)
-        } else if b'+' as u32 == c
-        {
-            Some(62)
-        } else if b'/' as u32 == c
-        {
-            Some(63)
-        } else if b'=' as u32 == c
-        {
-            Some(0)
-        } else
-        {
-            eprintln!("invalid char: '{original_char}'");
-
-            None
-        };
-
-        value.map(|x| x as u8)
-    }
 }
 
 struct GameWithEvents<'a>